@@ -112,19 +112,13 @@ int main(int, char**) {
     cameraTransform.Position.y = 2.0f;
     cameraTransform.Position.z = -5.0f;
 
-    glm::mat4 projectionMatrix =
-        glm::perspective(glm::radians(60.0f), (float)window->GetWidth() / (float)window->GetHeight(), 0.001f, 1000.0f);
+    glm::mat4 projectionMatrix = glm::perspective(glm::radians(60.0f), window->GetAspectRatio(), 0.001f, 1000.0f);
 
     window->SetResizeCallback([&](Window&, uint32_t width, uint32_t height) {
-        projectionMatrix =
-            glm::perspective(glm::radians(60.0f), (float)window->GetWidth() / (float)window->GetHeight(), 0.001f, 1000.0f);
+        projectionMatrix = glm::perspective(glm::radians(60.0f), window->GetAspectRatio(), 0.001f, 1000.0f);
     });
 
-    // TODO: Should the window keep track of this?
-    bool keys[KeyCode_MaxCount] = {};
     window->SetKeyCallback([&](Window&, KeyCode key, bool pressed) {
-        keys[key] = pressed;
-
         if (key == KeyCode_Escape && pressed) {
             if (window->IsCursorEnabled()) {
                 window->DisableCursor();
@@ -271,7 +265,7 @@ int main(int, char**) {
         {
             // Camera
             {
-                float speed       = keys[KeyCode_Control] ? 5.0f : 2.0f;
+                float speed       = window->IsKeyDown(KeyCode_Control) ? 5.0f : 2.0f;
                 glm::vec3 forward = cameraTransform.GetForward();
                 glm::vec3 right   = cameraTransform.GetRight();
                 glm::vec3 up      = cameraTransform.GetUp();
@@ -286,22 +280,23 @@ int main(int, char**) {
                     glm::rotate(glm::identity<glm::quat>(), cameraMovement.y, right) * cameraTransform.Rotation;
 
                 float cameraRotationSpeed = 90.0f;
-                float cameraRotation =
-                    ((keys[KeyCode_Q] ? cameraRotationSpeed : 0) + (keys[KeyCode_E] ? -cameraRotationSpeed : 0)) * dt;
+                float cameraRotation      = ((window->IsKeyDown(KeyCode_Q) ? cameraRotationSpeed : 0) +
+                                        (window->IsKeyDown(KeyCode_E) ? -cameraRotationSpeed : 0)) *
+                                       dt;
                 cameraTransform.Rotation =
                     glm::rotate(glm::identity<glm::quat>(), glm::radians(cameraRotation), forward) * cameraTransform.Rotation;
 
-                if (keys[KeyCode_W])
+                if (window->IsKeyDown(KeyCode_W))
                     cameraTransform.Position += forward * speed * dt;
-                if (keys[KeyCode_S])
+                if (window->IsKeyDown(KeyCode_S))
                     cameraTransform.Position -= forward * speed * dt;
-                if (keys[KeyCode_A])
+                if (window->IsKeyDown(KeyCode_A))
                     cameraTransform.Position -= right * speed * dt;
-                if (keys[KeyCode_D])
+                if (window->IsKeyDown(KeyCode_D))
                     cameraTransform.Position += right * speed * dt;
-                if (keys[KeyCode_Space])
+                if (window->IsKeyDown(KeyCode_Space))
                     cameraTransform.Position += up * speed * dt;
-                if (keys[KeyCode_Shift])
+                if (window->IsKeyDown(KeyCode_Shift))
                     cameraTransform.Position -= up * speed * dt;
             }
 
@@ -378,10 +373,9 @@ int main(int, char**) {
             renderer->EndScene();
 
             // UI
-            renderer->BeginScene(
-                glm::identity<glm::mat4>(),
-                glm::scale(glm::identity<glm::mat4>(), { 1.0f, (float)window->GetWidth() / (float)window->GetHeight(), 1.0f }),
-                false);
+            renderer->BeginScene(glm::identity<glm::mat4>(),
+                                 glm::scale(glm::identity<glm::mat4>(), { 1.0f, window->GetAspectRatio(), 1.0f }),
+                                 false);
             renderer->DrawIndexed(crosshairMesh.VertexBuffer,
                                   crosshairMesh.IndexBuffer,
                                   shader,
@@ -73,8 +73,8 @@ int main(int, char**) {
     float cameraZoom = 1.0f;
 
     auto calculateProjectionMatrix = [&]() -> glm::mat4 {
-        return glm::ortho(-(float)window->GetWidth() / (float)window->GetHeight() * cameraZoom,
-                          (float)window->GetWidth() / (float)window->GetHeight() * cameraZoom,
+        return glm::ortho(-window->GetAspectRatio() * cameraZoom,
+                          window->GetAspectRatio() * cameraZoom,
                           -cameraZoom,
                           cameraZoom,
                           -1.0f,
@@ -96,11 +96,6 @@ int main(int, char**) {
         cameraProjectionMatrix = calculateProjectionMatrix();
     });
 
-    bool keys[KeyCode_MaxCount] = {};
-    window->SetKeyCallback([&](Window&, KeyCode key, bool pressed) {
-        keys[key] = pressed;
-    });
-
     Ref<Renderer> renderer = Renderer::CreateOpenGLRenderer(window);
 
     Ref<Shader> circleShader = renderer->CreateShader("Circle.shader");
@@ -148,13 +143,13 @@ int main(int, char**) {
         {
             float CameraSpeed = 3.0f * cameraZoom;
 
-            if (keys[KeyCode_W])
+            if (window->IsKeyDown(KeyCode_W))
                 cameraTransform.Position += cameraTransform.GetUp() * CameraSpeed * dt;
-            if (keys[KeyCode_S])
+            if (window->IsKeyDown(KeyCode_S))
                 cameraTransform.Position += -cameraTransform.GetUp() * CameraSpeed * dt;
-            if (keys[KeyCode_A])
+            if (window->IsKeyDown(KeyCode_A))
                 cameraTransform.Position += -cameraTransform.GetRight() * CameraSpeed * dt;
-            if (keys[KeyCode_D])
+            if (window->IsKeyDown(KeyCode_D))
                 cameraTransform.Position += cameraTransform.GetRight() * CameraSpeed * dt;
         }
 
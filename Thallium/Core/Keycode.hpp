@@ -29,6 +29,8 @@ namespace Thallium {
         MouseButton_Left,
         MouseButton_Middle,
         MouseButton_Right,
+
+        MouseButton_MaxCount,
     };
 
 }
@@ -9,6 +9,23 @@
 
 namespace Thallium {
 
+    enum CursorIcon {
+        CursorIcon_Arrow,
+        CursorIcon_Hand,
+        CursorIcon_Text,
+        CursorIcon_Crosshair,
+        CursorIcon_Move,
+        CursorIcon_ResizeHorizontal,
+        CursorIcon_ResizeVertical,
+        CursorIcon_Wait,
+        CursorIcon_NotAllowed,
+    };
+
+    enum WindowTheme {
+        WindowTheme_Light,
+        WindowTheme_Dark,
+    };
+
     class Window: public IRef {
     protected:
         Window() = default;
@@ -20,6 +37,7 @@ namespace Thallium {
         using ScrollCallbackFunc           = std::function<void(Window& window, int8_t direction)>;
         using MouseMoveCallbackFunc        = std::function<void(Window& window, int32_t x, int32_t y)>;
         using RawMouseMovementCallbackFunc = std::function<void(Window& window, int32_t deltaX, int32_t deltaY)>;
+        using ThemeCallbackFunc            = std::function<void(Window& window, WindowTheme theme)>;
     public:
         Window(const Window&) = delete;
         Window(Window&&)      = delete;
@@ -27,15 +45,45 @@ namespace Thallium {
     public:
         virtual void Update() = 0;
 
-        virtual void Show() = 0;
-        virtual void Hide() = 0;
+        virtual void Show()   = 0;
+        virtual void Hide()   = 0;
+        virtual void Center() = 0; // Centers the window on the primary monitor's work area
 
         virtual void EnableCursor()    = 0;
         virtual void DisableCursor()   = 0;
         virtual bool IsCursorEnabled() = 0;
 
+        virtual void SetCursorIcon(CursorIcon icon) = 0;
+        virtual CursorIcon GetCursorIcon()          = 0;
+
         virtual uint32_t GetWidth()  = 0;
         virtual uint32_t GetHeight() = 0;
+
+        virtual bool IsKeyDown(KeyCode key) = 0;
+
+        virtual bool IsMouseButtonDown(MouseButton button)     = 0;
+        virtual bool IsMouseButtonPressed(MouseButton button)  = 0; // Pressed since the last call to Update
+        virtual bool IsMouseButtonReleased(MouseButton button) = 0; // Released since the last call to Update
+        virtual int32_t GetMouseX()                            = 0; // Relative to the client area
+        virtual int32_t GetMouseY()                            = 0; // Relative to the client area
+        virtual int32_t GetMouseDeltaX()                       = 0; // Raw movement since the last call to Update
+        virtual int32_t GetMouseDeltaY()                       = 0; // Raw movement since the last call to Update
+
+        // Scrolling since the last call to Update, measured in wheel notches (fractional for high resolution wheels)
+        // Positive x scrolls right, positive y scrolls away from the user
+        virtual float GetScrollDeltaX() = 0;
+        virtual float GetScrollDeltaY() = 0;
+
+        // Platforms that don't report a theme always return WindowTheme_Light
+        virtual WindowTheme GetTheme() = 0;
+
+        // Returns 1.0 if the height is zero, the Windows backend keeps the last non-zero size while minimized
+        float GetAspectRatio() {
+            uint32_t height = GetHeight();
+            if (height == 0)
+                return 1.0f;
+            return (float)GetWidth() / (float)height;
+        }
     public:
         virtual void SetCloseCallback(CloseCallbackFunc callback)                       = 0;
         virtual void SetResizeCallback(ResizeCallbackFunc callback)                     = 0;
@@ -44,8 +92,15 @@ namespace Thallium {
         virtual void SetScrollCallback(ScrollCallbackFunc callback)                     = 0;
         virtual void SetMouseMoveCallback(MouseMoveCallbackFunc callback)               = 0;
         virtual void SetRawMouseMovementCallback(RawMouseMovementCallbackFunc callback) = 0;
+        virtual void SetThemeCallback(ThemeCallbackFunc callback)                       = 0;
     public:
         static Ref<Window> Create(uint32_t width, uint32_t height, const std::string& title);
+        // Sizes the window to a fraction in (0, 1] of the primary monitor's work area and centers it, falling back to the
+        // given size if the work area is unavailable
+        static Ref<Window> Create(const std::string& title,
+                                  float workAreaFraction  = 0.8f,
+                                  uint32_t fallbackWidth  = 640,
+                                  uint32_t fallbackHeight = 480);
     };
 
 }
@@ -1,21 +1,61 @@
 #include "Thallium/Core/Windows/WindowsWindow.hpp"
 
 #include <iostream>
+#include <cassert>
 #include <format>
+#include <string_view>
 
 #if defined(_WIN32) || defined(_WIN64)
 
+    #include <windowsx.h>
+
 namespace Thallium {
 
     Ref<Window> Window::Create(uint32_t width, uint32_t height, const std::string& title) {
         return Ref<WindowsWindow>::Create(width, height, title);
     }
 
+    Ref<Window> Window::Create(const std::string& title,
+                               float workAreaFraction,
+                               uint32_t fallbackWidth,
+                               uint32_t fallbackHeight) {
+        assert(workAreaFraction > 0.0f && workAreaFraction <= 1.0f);
+
+        RECT workArea = {};
+        if (!SystemParametersInfoA(SPI_GETWORKAREA, 0, &workArea, 0) || workArea.right <= workArea.left ||
+            workArea.bottom <= workArea.top) {
+            return Ref<WindowsWindow>::Create(fallbackWidth, fallbackHeight, title);
+        }
+
+        uint32_t width            = static_cast<uint32_t>(static_cast<float>(workArea.right - workArea.left) * workAreaFraction);
+        uint32_t height           = static_cast<uint32_t>(static_cast<float>(workArea.bottom - workArea.top) * workAreaFraction);
+        Ref<WindowsWindow> window = Ref<WindowsWindow>::Create(width, height, title);
+        window->Center();
+        return window;
+    }
+
     constexpr const char* WindowClassName = "Physics";
     static bool WindowClassInitialised    = false;
 
+    static WindowTheme GetSystemTheme() {
+        DWORD appsUseLightTheme = 1;
+        DWORD size              = sizeof(appsUseLightTheme);
+        // The value is missing on versions of Windows without a dark mode, in which case the theme is light
+        if (RegGetValueA(HKEY_CURRENT_USER,
+                         "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize",
+                         "AppsUseLightTheme",
+                         RRF_RT_REG_DWORD,
+                         nullptr,
+                         &appsUseLightTheme,
+                         &size) != ERROR_SUCCESS) {
+            return WindowTheme_Light;
+        }
+        return appsUseLightTheme ? WindowTheme_Light : WindowTheme_Dark;
+    }
+
     WindowsWindow::WindowsWindow(uint32_t width, uint32_t height, const std::string& title) {
         Instance = GetModuleHandleA(nullptr);
+        Cursor   = LoadCursor(nullptr, IDC_ARROW);
 
         if (!WindowClassInitialised) {
             WNDCLASSEXA windowClass = {
@@ -38,6 +78,7 @@ namespace Thallium {
 
         Width  = width;
         Height = height;
+        Theme  = GetSystemTheme();
 
         RECT windowRect;
         windowRect.left   = 100;
@@ -89,6 +130,15 @@ namespace Thallium {
     }
 
     void WindowsWindow::Update() {
+        MouseDeltaX  = 0;
+        MouseDeltaY  = 0;
+        ScrollDeltaX = 0.0f;
+        ScrollDeltaY = 0.0f;
+        for (size_t i = 0; i < MouseButton_MaxCount; i++) {
+            MouseButtonsPressed[i]  = false;
+            MouseButtonsReleased[i] = false;
+        }
+
         MSG message;
         while (PeekMessageA(&message, WindowHandle, 0, 0, PM_REMOVE)) {
             TranslateMessage(&message);
@@ -104,6 +154,23 @@ namespace Thallium {
         ShowWindow(WindowHandle, SW_HIDE);
     }
 
+    void WindowsWindow::Center() {
+        RECT workArea = {};
+        if (!SystemParametersInfoA(SPI_GETWORKAREA, 0, &workArea, 0)) {
+            std::cerr << std::format("Unable to get the monitor work area: 0x{:x}", GetLastError()) << std::endl;
+            return;
+        }
+
+        RECT windowRect = {};
+        GetWindowRect(WindowHandle, &windowRect);
+        LONG windowWidth  = windowRect.right - windowRect.left;
+        LONG windowHeight = windowRect.bottom - windowRect.top;
+
+        LONG x = workArea.left + (workArea.right - workArea.left - windowWidth) / 2;
+        LONG y = workArea.top + (workArea.bottom - workArea.top - windowHeight) / 2;
+        SetWindowPos(WindowHandle, nullptr, x, y, 0, 0, SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE);
+    }
+
     void WindowsWindow::EnableCursor() {
         while (ShowCursor(true) < 0) {
         }
@@ -121,6 +188,66 @@ namespace Thallium {
         CursorDisabled = true;
     }
 
+    void WindowsWindow::SetCursorIcon(CursorIcon icon) {
+        LPCTSTR cursorName = IDC_ARROW;
+        switch (icon) {
+            case CursorIcon_Arrow: {
+                cursorName = IDC_ARROW;
+            } break;
+
+            case CursorIcon_Hand: {
+                cursorName = IDC_HAND;
+            } break;
+
+            case CursorIcon_Text: {
+                cursorName = IDC_IBEAM;
+            } break;
+
+            case CursorIcon_Crosshair: {
+                cursorName = IDC_CROSS;
+            } break;
+
+            case CursorIcon_Move: {
+                cursorName = IDC_SIZEALL;
+            } break;
+
+            case CursorIcon_ResizeHorizontal: {
+                cursorName = IDC_SIZEWE;
+            } break;
+
+            case CursorIcon_ResizeVertical: {
+                cursorName = IDC_SIZENS;
+            } break;
+
+            case CursorIcon_Wait: {
+                cursorName = IDC_WAIT;
+            } break;
+
+            case CursorIcon_NotAllowed: {
+                cursorName = IDC_NO;
+            } break;
+        }
+
+        HCURSOR cursor = LoadCursor(nullptr, cursorName);
+        if (!cursor) {
+            std::cerr << std::format("Unable to load cursor: 0x{:x}", GetLastError()) << std::endl;
+            return;
+        }
+        Cursor = cursor;
+        Icon   = icon;
+
+        // WM_SETCURSOR only arrives when the mouse moves, so update the cursor straight away if it is over the client area
+        POINT point = {};
+        RECT rect   = {};
+        GetCursorPos(&point);
+        if (WindowFromPoint(point) == WindowHandle) {
+            ScreenToClient(WindowHandle, &point);
+            GetClientRect(WindowHandle, &rect);
+            if (PtInRect(&rect, point))
+                SetCursor(Cursor);
+        }
+    }
+
     LRESULT WINAPI WindowsWindow::StaticWindowMessageCallback(HWND hWnd, UINT message, WPARAM wParam, LPARAM lParam) {
         if (message == WM_NCCREATE) {
             CREATESTRUCTA* createStruct = reinterpret_cast<CREATESTRUCTA*>(lParam);
@@ -145,16 +272,16 @@ namespace Thallium {
             } break;
 
             case WM_SIZE: {
-                if (ResizeCallback) {
-                    RECT windowRect = {};
-                    GetClientRect(hWnd, &windowRect);
-                    LONG windowWidth  = windowRect.right - windowRect.left;
-                    LONG windowHeight = windowRect.bottom - windowRect.top;
-                    if (windowWidth > 0 && windowHeight > 0) {
-                        Width  = static_cast<uint32_t>(windowWidth);
-                        Height = static_cast<uint32_t>(windowHeight);
+                RECT windowRect = {};
+                GetClientRect(hWnd, &windowRect);
+                LONG windowWidth  = windowRect.right - windowRect.left;
+                LONG windowHeight = windowRect.bottom - windowRect.top;
+                // Zero sizes (e.g. when minimized) are ignored, so the last non-zero size is kept
+                if (windowWidth > 0 && windowHeight > 0) {
+                    Width  = static_cast<uint32_t>(windowWidth);
+                    Height = static_cast<uint32_t>(windowHeight);
+                    if (ResizeCallback)
                         ResizeCallback(*this, Width, Height);
-                    }
                 }
             } break;
 
@@ -162,54 +289,56 @@ namespace Thallium {
             case WM_KEYUP:
             case WM_SYSKEYDOWN:
             case WM_SYSKEYUP: {
+                bool pressed = message == WM_KEYDOWN || message == WM_SYSKEYDOWN;
+                KeyCode key  = KeyCode_Unknown;
+                switch (wParam) {
+                    case 'W': {
+                        key = KeyCode_W;
+                    } break;
+
+                    case 'S': {
+                        key = KeyCode_S;
+                    } break;
+
+                    case 'A': {
+                        key = KeyCode_A;
+                    } break;
+
+                    case 'D': {
+                        key = KeyCode_D;
+                    } break;
+
+                    case 'Q': {
+                        key = KeyCode_Q;
+                    } break;
+
+                    case 'E': {
+                        key = KeyCode_E;
+                    } break;
+
+                    case VK_SHIFT: {
+                        key = KeyCode_Shift;
+                    } break;
+
+                    case VK_CONTROL: {
+                        key = KeyCode_Control;
+                    } break;
+
+                    case VK_SPACE: {
+                        key = KeyCode_Space;
+                    } break;
+
+                    case VK_ESCAPE: {
+                        key = KeyCode_Escape;
+                    } break;
+
+                    default: {
+                        key = KeyCode_Unknown;
+                    } break;
+                }
+                if (key != KeyCode_Unknown)
+                    KeysDown[key] = pressed;
                 if (KeyCallback) {
-                    bool pressed = message == WM_KEYDOWN || message == WM_SYSKEYDOWN;
-                    KeyCode key  = KeyCode_Unknown;
-                    switch (wParam) {
-                        case 'W': {
-                            key = KeyCode_W;
-                        } break;
-
-                        case 'S': {
-                            key = KeyCode_S;
-                        } break;
-
-                        case 'A': {
-                            key = KeyCode_A;
-                        } break;
-
-                        case 'D': {
-                            key = KeyCode_D;
-                        } break;
-
-                        case 'Q': {
-                            key = KeyCode_Q;
-                        } break;
-
-                        case 'E': {
-                            key = KeyCode_E;
-                        } break;
-
-                        case VK_SHIFT: {
-                            key = KeyCode_Shift;
-                        } break;
-
-                        case VK_CONTROL: {
-                            key = KeyCode_Control;
-                        } break;
-
-                        case VK_SPACE: {
-                            key = KeyCode_Space;
-                        } break;
-
-                        case VK_ESCAPE: {
-                            key = KeyCode_Escape;
-                        } break;
-
-                        default: {
-                            key = KeyCode_Unknown;
-                        } break;
-                    }
                     for (size_t i = 0; i < (size_t)(lParam & 0xFFFF); i++) {
                         KeyCallback(*this, key, pressed);
                     }
@@ -234,61 +363,119 @@ namespace Thallium {
                     }
                     ClipCursor(nullptr);
                 }
-            } break;
 
-            case WM_INPUT: {
-                if (RawMouseMovementCallback) {
-                    HRAWINPUT rawInput = reinterpret_cast<HRAWINPUT>(lParam);
-                    UINT size          = 0;
-                    if (GetRawInputData(rawInput, RID_INPUT, nullptr, &size, sizeof(RAWINPUTHEADER)) == UINT_MAX) {
-                        std::cerr << std::format("Unable to get the size of the mouse input data: {}", GetLastError())
-                                  << std::endl;
-                        break;
+                // The release messages for anything still held won't be sent to this window, so release them here
+                for (size_t i = 0; i < KeyCode_MaxCount; i++) {
+                    if (KeysDown[i]) {
+                        KeysDown[i] = false;
+                        if (KeyCallback)
+                            KeyCallback(*this, static_cast<KeyCode>(i), false);
                     }
-                    char* bytes = new char[size];
-                    defer(delete[] bytes);
-                    if (GetRawInputData(rawInput, RID_INPUT, bytes, &size, sizeof(RAWINPUTHEADER)) == UINT_MAX) {
-                        std::cerr << std::format("Unable to get the mouse input data: {}", GetLastError()) << std::endl;
-                        break;
+                }
+                ReleaseMouseButtons();
+            } break;
+
+            case WM_CAPTURECHANGED: {
+                // Another window took the mouse capture, so the button release messages won't be sent to this window
+                if (reinterpret_cast<HWND>(lParam) != hWnd)
+                    ReleaseMouseButtons();
+            } break;
+
+            case WM_SETTINGCHANGE: {
+                const char* setting = reinterpret_cast<const char*>(lParam);
+                if (setting && std::string_view(setting) == "ImmersiveColorSet") {
+                    WindowTheme theme = GetSystemTheme();
+                    if (theme != Theme) {
+                        Theme = theme;
+                        if (ThemeCallback)
+                            ThemeCallback(*this, Theme);
                     }
-                    RAWINPUT* input = reinterpret_cast<RAWINPUT*>(bytes);
-                    int32_t mouseX  = static_cast<int32_t>(input->data.mouse.lLastX);
-                    int32_t mouseY  = static_cast<int32_t>(input->data.mouse.lLastY);
-                    RawMouseMovementCallback(*this, mouseX, mouseY);
                 }
+                result = DefWindowProcA(hWnd, message, wParam, lParam);
+            } break;
+
+            case WM_SETCURSOR: {
+                if (LOWORD(lParam) == HTCLIENT) {
+                    SetCursor(Cursor);
+                    result = TRUE;
+                } else {
+                    result = DefWindowProcA(hWnd, message, wParam, lParam);
+                }
+            } break;
+
+            case WM_INPUT: {
+                HRAWINPUT rawInput = reinterpret_cast<HRAWINPUT>(lParam);
+                UINT size          = 0;
+                if (GetRawInputData(rawInput, RID_INPUT, nullptr, &size, sizeof(RAWINPUTHEADER)) == UINT_MAX) {
+                    std::cerr << std::format("Unable to get the size of the mouse input data: {}", GetLastError()) << std::endl;
+                    break;
+                }
+                char* bytes = new char[size];
+                defer(delete[] bytes);
+                if (GetRawInputData(rawInput, RID_INPUT, bytes, &size, sizeof(RAWINPUTHEADER)) == UINT_MAX) {
+                    std::cerr << std::format("Unable to get the mouse input data: {}", GetLastError()) << std::endl;
+                    break;
+                }
+                RAWINPUT* input = reinterpret_cast<RAWINPUT*>(bytes);
+                int32_t mouseX  = static_cast<int32_t>(input->data.mouse.lLastX);
+                int32_t mouseY  = static_cast<int32_t>(input->data.mouse.lLastY);
+                MouseDeltaX += mouseX;
+                MouseDeltaY += mouseY;
+                if (RawMouseMovementCallback)
+                    RawMouseMovementCallback(*this, mouseX, mouseY);
             } break;
 
             case WM_LBUTTONDOWN: {
+                MouseButtonsDown[MouseButton_Left]    = true;
+                MouseButtonsPressed[MouseButton_Left] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Left, true);
             } break;
 
             case WM_LBUTTONUP: {
+                MouseButtonsDown[MouseButton_Left]     = false;
+                MouseButtonsReleased[MouseButton_Left] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Left, false);
             } break;
 
             case WM_MBUTTONDOWN: {
+                MouseButtonsDown[MouseButton_Middle]    = true;
+                MouseButtonsPressed[MouseButton_Middle] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Middle, true);
             } break;
 
             case WM_MBUTTONUP: {
+                MouseButtonsDown[MouseButton_Middle]     = false;
+                MouseButtonsReleased[MouseButton_Middle] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Middle, false);
             } break;
 
             case WM_RBUTTONDOWN: {
+                MouseButtonsDown[MouseButton_Right]    = true;
+                MouseButtonsPressed[MouseButton_Right] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Right, true);
             } break;
 
             case WM_RBUTTONUP: {
+                MouseButtonsDown[MouseButton_Right]     = false;
+                MouseButtonsReleased[MouseButton_Right] = true;
+                UpdateMouseCapture();
                 if (MouseButtonCallback)
                     MouseButtonCallback(*this, MouseButton_Right, false);
             } break;
 
             case WM_MOUSEWHEEL: {
+                ScrollDeltaY += static_cast<float>(GET_WHEEL_DELTA_WPARAM(wParam)) / static_cast<float>(WHEEL_DELTA);
+
                 int8_t direction = 0;
                 if (GET_WHEEL_DELTA_WPARAM(wParam) > 0) {
                     direction = 1;
@@ -299,9 +486,15 @@ namespace Thallium {
                     ScrollCallback(*this, direction);
             } break;
 
+            case WM_MOUSEHWHEEL: {
+                ScrollDeltaX += static_cast<float>(GET_WHEEL_DELTA_WPARAM(wParam)) / static_cast<float>(WHEEL_DELTA);
+            } break;
+
             case WM_MOUSEMOVE: {
+                MouseX = GET_X_LPARAM(lParam);
+                MouseY = GET_Y_LPARAM(lParam);
                 if (MouseMoveCallback)
-                    MouseMoveCallback(*this, LOWORD(lParam), HIWORD(lParam));
+                    MouseMoveCallback(*this, MouseX, MouseY);
             } break;
 
             default: {
@@ -312,6 +505,34 @@ namespace Thallium {
         return result;
     }
 
+    void WindowsWindow::UpdateMouseCapture() {
+        bool anyMouseButtonDown = false;
+        for (size_t i = 0; i < MouseButton_MaxCount; i++) {
+            if (MouseButtonsDown[i])
+                anyMouseButtonDown = true;
+        }
+
+        // Keep receiving the mouse messages while a button is held, even if the cursor leaves the window
+        if (anyMouseButtonDown && GetCapture() != WindowHandle) {
+            SetCapture(WindowHandle);
+        } else if (!anyMouseButtonDown && GetCapture() == WindowHandle) {
+            ReleaseCapture();
+        }
+    }
+
+    void WindowsWindow::ReleaseMouseButtons() {
+        for (size_t i = 0; i < MouseButton_MaxCount; i++) {
+            if (MouseButtonsDown[i]) {
+                MouseButtonsDown[i]     = false;
+                MouseButtonsReleased[i] = true;
+                if (MouseButtonCallback)
+                    MouseButtonCallback(*this, static_cast<MouseButton>(i), false);
+            }
+        }
+        // Drop the capture as well, otherwise the button up messages still arrive and release the buttons a second time
+        UpdateMouseCapture();
+    }
+
 }
 
 #endif
@@ -19,6 +19,7 @@ namespace Thallium {
 
         void Show() final;
         void Hide() final;
+        void Center() final;
 
         void EnableCursor() final;
         void DisableCursor() final;
@@ -26,12 +27,53 @@ namespace Thallium {
             return !CursorDisabled;
         }
 
+        void SetCursorIcon(CursorIcon icon) final;
+        CursorIcon GetCursorIcon() final {
+            return Icon;
+        }
+
         uint32_t GetWidth() final {
             return Width;
         }
         uint32_t GetHeight() final {
             return Height;
         }
+
+        bool IsKeyDown(KeyCode key) final {
+            return KeysDown[key];
+        }
+
+        bool IsMouseButtonDown(MouseButton button) final {
+            return MouseButtonsDown[button];
+        }
+        bool IsMouseButtonPressed(MouseButton button) final {
+            return MouseButtonsPressed[button];
+        }
+        bool IsMouseButtonReleased(MouseButton button) final {
+            return MouseButtonsReleased[button];
+        }
+        int32_t GetMouseX() final {
+            return MouseX;
+        }
+        int32_t GetMouseY() final {
+            return MouseY;
+        }
+        int32_t GetMouseDeltaX() final {
+            return MouseDeltaX;
+        }
+        int32_t GetMouseDeltaY() final {
+            return MouseDeltaY;
+        }
+        float GetScrollDeltaX() final {
+            return ScrollDeltaX;
+        }
+        float GetScrollDeltaY() final {
+            return ScrollDeltaY;
+        }
+
+        WindowTheme GetTheme() final {
+            return Theme;
+        }
     public:
         void SetCloseCallback(CloseCallbackFunc callback) final {
             CloseCallback = callback;
@@ -54,17 +96,36 @@ namespace Thallium {
         void SetRawMouseMovementCallback(RawMouseMovementCallbackFunc callback) final {
             RawMouseMovementCallback = callback;
         }
+        void SetThemeCallback(ThemeCallbackFunc callback) final {
+            ThemeCallback = callback;
+        }
     private:
         static LRESULT WINAPI StaticWindowMessageCallback(HWND hWnd, UINT message, WPARAM wParam, LPARAM lParam);
         LRESULT WINAPI WindowMessageCallback(HWND hWnd, UINT message, WPARAM wParam, LPARAM lParam);
+        void UpdateMouseCapture();
+        void ReleaseMouseButtons();
     private:
         HINSTANCE Instance = nullptr;
         HWND WindowHandle  = nullptr;
         HDC DeviceContext  = nullptr;
+        HCURSOR Cursor     = nullptr;
     private:
         uint32_t Width      = 0;
         uint32_t Height     = 0;
         bool CursorDisabled = false;
+        CursorIcon Icon     = CursorIcon_Arrow;
+        WindowTheme Theme   = WindowTheme_Light;
+    private:
+        bool KeysDown[KeyCode_MaxCount]                 = {};
+        bool MouseButtonsDown[MouseButton_MaxCount]     = {};
+        bool MouseButtonsPressed[MouseButton_MaxCount]  = {};
+        bool MouseButtonsReleased[MouseButton_MaxCount] = {};
+        int32_t MouseX                                  = 0;
+        int32_t MouseY                                  = 0;
+        int32_t MouseDeltaX                             = 0;
+        int32_t MouseDeltaY                             = 0;
+        float ScrollDeltaX                              = 0.0f;
+        float ScrollDeltaY                              = 0.0f;
     private:
         CloseCallbackFunc CloseCallback                       = nullptr;
         ResizeCallbackFunc ResizeCallback                     = nullptr;
@@ -73,6 +134,7 @@ namespace Thallium {
         ScrollCallbackFunc ScrollCallback                     = nullptr;
         MouseMoveCallbackFunc MouseMoveCallback               = nullptr;
         RawMouseMovementCallbackFunc RawMouseMovementCallback = nullptr;
+        ThemeCallbackFunc ThemeCallback                       = nullptr;
     };
 
 }
@@ -81,6 +81,13 @@ namespace Thallium {
         OPENGL_FUNCTIONS
     #undef OPENGL_FUNCTION
 
+        // WGL_EXT_swap_control is optional, so vsync control is only available when the driver provides it
+        wglSwapIntervalEXT    = reinterpret_cast<SwapIntervalFunc>(wglGetProcAddress("wglSwapIntervalEXT"));
+        wglGetSwapIntervalEXT = reinterpret_cast<GetSwapIntervalFunc>(wglGetProcAddress("wglGetSwapIntervalEXT"));
+        if (wglGetSwapIntervalEXT) {
+            VSyncEnabled = wglGetSwapIntervalEXT() != 0;
+        }
+
         glEnable(GL_DEBUG_OUTPUT);
         glEnable(GL_DEBUG_OUTPUT_SYNCHRONOUS);
         glDebugMessageCallback(
@@ -125,6 +132,20 @@ namespace Thallium {
         SwapBuffers(Window->DeviceContext);
     }
 
+    void WindowsOpenGLRenderer::SetVSync(bool enabled) {
+        if (!wglSwapIntervalEXT) {
+            std::cerr << "Unable to set vsync: wglSwapIntervalEXT is not supported" << std::endl;
+            return;
+        }
+
+        MakeContextCurrent();
+        if (!wglSwapIntervalEXT(enabled ? 1 : 0)) {
+            std::cerr << std::format("Unable to set swap interval: 0x{:x}", GetLastError()) << std::endl;
+            return;
+        }
+        VSyncEnabled = enabled;
+    }
+
     void WindowsOpenGLRenderer::MakeContextCurrent() {
         if (wglGetCurrentContext() != OpenGLContext) {
             if (!wglMakeCurrent(Window->DeviceContext, OpenGLContext)) {
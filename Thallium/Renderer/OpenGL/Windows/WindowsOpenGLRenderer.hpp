@@ -13,6 +13,11 @@ namespace Thallium {
         ~WindowsOpenGLRenderer() final;
     public:
         void Present() final;
+    public:
+        void SetVSync(bool enabled) final;
+        bool IsVSyncEnabled() const final {
+            return VSyncEnabled;
+        }
     public:
         Ref<Window> GetWindow() const {
             return Window;
@@ -20,9 +25,15 @@ namespace Thallium {
     protected:
         void MakeContextCurrent() final;
     private:
-        Ref<WindowsWindow> Window = nullptr;
-        HGLRC OpenGLContext       = nullptr;
-        HMODULE OpenGLLibrary     = nullptr;
+        using SwapIntervalFunc    = BOOL(WINAPI*)(int interval);
+        using GetSwapIntervalFunc = int(WINAPI*)();
+    private:
+        Ref<WindowsWindow> Window                 = nullptr;
+        HGLRC OpenGLContext                       = nullptr;
+        HMODULE OpenGLLibrary                     = nullptr;
+        SwapIntervalFunc wglSwapIntervalEXT       = nullptr;
+        GetSwapIntervalFunc wglGetSwapIntervalEXT = nullptr;
+        bool VSyncEnabled                         = false;
     };
 
 }
@@ -40,6 +40,9 @@ namespace Thallium {
                                  const Transform& transform,
                                  const Material& material)              = 0;
         virtual void Present()                                          = 0;
+    public:
+        virtual void SetVSync(bool enabled) = 0;
+        virtual bool IsVSyncEnabled() const = 0;
     public:
         virtual Ref<Shader> CreateShader(const std::string& filepath) = 0;
         virtual Ref<VertexBuffer>
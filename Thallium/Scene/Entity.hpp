@@ -5,11 +5,13 @@
 
 #include <utility>
 #include <typeinfo>
+#include <functional>
 
 namespace Thallium {
 
     class Entity {
     public:
+        // A default constructed entity is a placeholder that never refers to a live entity
         Entity() : ID(UINT64_MAX), Scene(nullptr) {}
         Entity(Ref<Scene> scene) : ID(UINT64_MAX), Scene(std::move(scene)) {
             ID = Scene->CreateEntity();
@@ -20,6 +22,11 @@ namespace Thallium {
             Scene->DestroyEntity(ID);
         }
 
+        // Creates a new entity with a copy of every component on this entity
+        Entity Clone() {
+            return Entity(Scene->CloneEntity(ID), Scene);
+        }
+
         template<typename T>
         T& AddComponent(T&& value = T()) {
             return *std::any_cast<T>(&Scene->AddComponent(ID, std::make_any<T>(std::move(value))));
@@ -40,6 +47,36 @@ namespace Thallium {
             return *std::any_cast<T>(&Scene->GetComponent(ID, typeid(T)));
         }
 
+        // Copies the component onto other, replacing its existing one, returns false if this entity doesn't have it
+        template<typename T>
+        bool CopyComponentTo(Entity& other) {
+            if (!HasComponent<T>())
+                return false;
+            if (other.HasComponent<T>()) {
+                other.GetComponent<T>() = GetComponent<T>();
+            } else {
+                other.AddComponent<T>(T(GetComponent<T>()));
+            }
+            return true;
+        }
+
+        // Exchanges the component with other's, does nothing and returns false unless both entities have it
+        template<typename T>
+        bool SwapComponentWith(Entity& other) {
+            if (!HasComponent<T>() || !other.HasComponent<T>())
+                return false;
+            std::swap(GetComponent<T>(), other.GetComponent<T>());
+            return true;
+        }
+
+        std::vector<std::type_index> GetComponentTypes() const {
+            return Scene->GetComponentTypes(ID);
+        }
+
+        bool IsPlaceholder() const {
+            return !Scene;
+        }
+
         explicit operator bool() const {
             return Scene && Scene->EntityExists(ID);
         }
@@ -51,6 +88,13 @@ namespace Thallium {
         bool operator!=(const Entity& other) const {
             return !(*this == other);
         }
+
+        // Orders by scene and then by id, so entities can be used as keys in ordered containers
+        bool operator<(const Entity& other) const {
+            if (this->Scene.Raw() != other.Scene.Raw())
+                return std::less<>{}(this->Scene.Raw(), other.Scene.Raw());
+            return this->ID < other.ID;
+        }
     private:
         EntityID ID;
         Ref<Scene> Scene;
@@ -23,6 +23,10 @@ namespace Thallium {
         return new Scene();
     }
 
+    void Scene::ReserveEntities(size_t count) {
+        Entities.reserve(Entities.size() + count);
+    }
+
     EntityID Scene::CreateEntity() {
         EntityID entity = ++CurrentEntityID;
         Entities.insert({ entity, {} });
@@ -40,22 +44,46 @@ namespace Thallium {
         Entities.erase(Entities.find(entity));
     }
 
+    EntityID Scene::CloneEntity(EntityID entity) {
+        assert(EntityExists(entity));
+        EntityID clone = CreateEntity();
+        // Copy the type set, add component callbacks are allowed to modify the scene
+        std::set<std::type_index> types = Entities.at(entity);
+        for (auto& type : types) {
+            std::any component = Components.at(type).at(entity);
+            // A previous add component callback may have already added this type to the clone
+            if (HasComponent(clone, type))
+                Components.at(type).at(clone) = std::move(component);
+            else
+                AddComponent(clone, std::move(component));
+        }
+        return clone;
+    }
+
     bool Scene::EntityExists(EntityID entity) const {
         return Entities.contains(entity);
     }
 
     std::any& Scene::AddComponent(EntityID entity, std::any&& value) {
+        // The type has to be read before the value is moved into the component map
+        std::type_index type = value.type();
         assert(EntityExists(entity));
-        assert(!HasComponent(entity, value.type()));
-        if (!Components.contains(value.type())) {
-            Components.insert({ value.type(), {} });
+        assert(!HasComponent(entity, type));
+#ifndef NDEBUG
+        if (ComponentValidators.contains(type) && ComponentValidators[type] && !ComponentValidators[type](value)) {
+            std::cerr << std::format("Invalid {} component added to entity {}", type.name(), entity) << std::endl;
+            assert(false && "Component failed its validator");
         }
-        Components.at(value.type()).emplace(entity, std::move(value));
-        Entities.at(entity).insert(value.type());
-        if (AddComponentCallbacks.contains(value.type()) && AddComponentCallbacks[value.type()]) {
-            AddComponentCallbacks[value.type()](*this, entity);
+#endif
+        if (!Components.contains(type)) {
+            Components.insert({ type, {} });
         }
-        return Components.at(value.type()).at(entity);
+        Components.at(type).emplace(entity, std::move(value));
+        Entities.at(entity).insert(type);
+        if (AddComponentCallbacks.contains(type) && AddComponentCallbacks[type]) {
+            AddComponentCallbacks[type](*this, entity);
+        }
+        return Components.at(type).at(entity);
     }
 
     void Scene::RemoveComponent(EntityID entity, std::type_index type) {
@@ -73,6 +101,12 @@ namespace Thallium {
         return Entities.at(entity).contains(type);
     }
 
+    std::vector<std::type_index> Scene::GetComponentTypes(EntityID entity) const {
+        assert(EntityExists(entity));
+        const std::set<std::type_index>& types = Entities.at(entity);
+        return { types.begin(), types.end() };
+    }
+
     std::any& Scene::GetComponent(EntityID entity, std::type_index type) {
         assert(EntityExists(entity));
         assert(HasComponent(entity, type));
@@ -8,6 +8,7 @@
 #include <typeindex>
 #include <any>
 #include <set>
+#include <vector>
 
 namespace Thallium {
 
@@ -24,6 +25,15 @@ namespace Thallium {
     public:
         static Ref<Scene> Create();
     public:
+        // Reserves space for count more entities so a large batch of entities can be created without rehashing
+        void ReserveEntities(size_t count);
+
+        template<typename T>
+        void ReserveComponents(size_t count) {
+            auto& components = Components[typeid(T)];
+            components.reserve(components.size() + count);
+        }
+
         template<typename T>
         void SetAddComponentCallback(std::function<void(Scene&, EntityID, T&)> callback) {
             AddComponentCallbacks[typeid(T)] = [=](Scene& scene, EntityID id) {
@@ -31,6 +41,19 @@ namespace Thallium {
             };
         }
 
+        // Checked on every AddComponent before the value is inserted, a value failing the validator is logged and asserts
+        // Validators are only stored and run in debug builds
+        template<typename T>
+        void SetComponentValidator(std::function<bool(const T&)> validator) {
+#ifndef NDEBUG
+            ComponentValidators[typeid(T)] = [=](const std::any& value) {
+                return validator(*std::any_cast<T>(&value));
+            };
+#else
+            (void)validator;
+#endif
+        }
+
         template<typename T>
         void SetRemoveComponentCallback(std::function<void(Scene&, EntityID, T&)> callback) {
             RemoveComponentCallbacks[typeid(T)] = [=](Scene& scene, EntityID id) {
@@ -42,7 +65,7 @@ namespace Thallium {
         void IterateComponent(std::function<void(EntityID, T&)> func) {
             if (Components.contains(typeid(T))) {
                 for (auto& [entityID, component] : Components.at(typeid(T))) {
-                    func(entityID, *any_cast<float>(&component));
+                    func(entityID, *std::any_cast<T>(&component));
                 }
             }
         }
@@ -54,6 +77,8 @@ namespace Thallium {
     private:
         template<typename ComponentType, typename SecondComponentType, typename... ComponentTypes, typename... Types>
         void IterateComponentsImpl(EntityID entityFromParentCall, std::function<void(EntityID, Types&...)>& func) {
+            if (!Components.contains(typeid(ComponentType)))
+                return;
             for (auto& [entity, component] : Components.at(typeid(ComponentType))) {
                 if (!EntityExists(entityFromParentCall) || entity == entityFromParentCall) {
                     IterateComponentsImpl<SecondComponentType, ComponentTypes...>(entity, func);
@@ -63,6 +88,8 @@ namespace Thallium {
 
         template<typename ComponentType, typename... Types>
         void IterateComponentsImpl(EntityID entityFromParentCall, std::function<void(EntityID, Types&...)>& func) {
+            if (!Components.contains(typeid(ComponentType)))
+                return;
             for (auto& [entity, component] : Components.at(typeid(ComponentType))) {
                 if (!EntityExists(entityFromParentCall) || entity == entityFromParentCall) {
                     func(entity, *std::any_cast<Types>(&Components.at(typeid(Types)).at(entity))...);
@@ -72,10 +99,12 @@ namespace Thallium {
     private:
         EntityID CreateEntity();
         void DestroyEntity(EntityID entity);
+        EntityID CloneEntity(EntityID entity);
         bool EntityExists(EntityID entity) const;
         std::any& AddComponent(EntityID entity, std::any&& value);
         void RemoveComponent(EntityID entity, std::type_index type);
         bool HasComponent(EntityID entity, std::type_index type) const;
+        std::vector<std::type_index> GetComponentTypes(EntityID entity) const;
         std::any& GetComponent(EntityID entity, std::type_index type);
     private:
         EntityID CurrentEntityID = 0;
@@ -83,6 +112,9 @@ namespace Thallium {
         std::unordered_map<std::type_index, std::unordered_map<EntityID, std::any>> Components;
         std::unordered_map<std::type_index, std::function<void(Scene&, EntityID)>> AddComponentCallbacks;
         std::unordered_map<std::type_index, std::function<void(Scene&, EntityID)>> RemoveComponentCallbacks;
+#ifndef NDEBUG
+        std::unordered_map<std::type_index, std::function<bool(const std::any&)>> ComponentValidators;
+#endif
     };
 
 }